@@ -0,0 +1,40 @@
+//! Run with `cargo bench --features bench`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use iso_8601::parse_datetime;
+
+const INPUTS: &[&str] = &[
+    "2024",
+    "2024-02",
+    "202402",
+    "2024-02-11",
+    "20240211",
+    "2024-02-11T14",
+    "2024-02-11 14",
+    "2024-02-11T14:15",
+    "2024-02-11 14:15",
+    "2024-02-11T14:15:45",
+    "2024-02-11 14:15:45",
+    "2024-02-11T14:15:45+05:00",
+    "2024-02-11T14:15:45-05:00",
+    "2024-02-11T14:15:45+0500",
+    "2024-02-11T14:15:45-0445",
+    "2024-02-11T14:15:45+05",
+    "2024-02-11T14:15:45.250Z",
+    "2024-02-11T14:15:45,250+05:00",
+    "1707660945",
+    "1707660945000",
+];
+
+fn bench_parse_datetime(c: &mut Criterion) {
+    c.bench_function("parse_datetime", |b| {
+        b.iter(|| {
+            for input in INPUTS {
+                black_box(parse_datetime(black_box(input)).ok());
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_parse_datetime);
+criterion_main!(benches);
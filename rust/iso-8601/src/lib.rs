@@ -1,7 +1,80 @@
-use chrono::{DateTime, FixedOffset, TimeZone};
+use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Timelike, Utc};
+use std::fmt;
 
-pub fn parse_datetime(str: &str) -> Option<DateTime<FixedOffset>> {
-    let values = DateTimeValues::from(str);
+/// Why a [`parse_datetime`] call failed, and where in the input it happened.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// The parser expected a digit or a known separator at this byte offset
+    /// and found something else.
+    UnexpectedToken { at: usize },
+    /// A field's characters were read but didn't form a valid number for
+    /// that state.
+    InvalidField(ParserState),
+    /// Every field parsed, but together they don't form a valid calendar
+    /// date/time/offset (e.g. month 99, or an offset that doesn't exist).
+    OutOfRange,
+    /// The input was fully understood, but bytes remained after the
+    /// timezone field.
+    TrailingInput { at: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedToken { at } => write!(f, "unexpected token at byte {at}"),
+            ParseError::InvalidField(state) => write!(f, "invalid {state:?} field"),
+            ParseError::OutOfRange => write!(f, "fields don't form a valid datetime"),
+            ParseError::TrailingInput { at } => write!(f, "trailing input at byte {at}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn parse_datetime(str: &str) -> Result<DateTime<FixedOffset>, ParseError> {
+    let values = DateTimeValues::from(str)?;
+    let tz = FixedOffset::east_opt(values.timezone.secs()).ok_or(ParseError::OutOfRange)?;
+    tz.with_ymd_and_hms(
+        values.year,
+        values.month,
+        values.date,
+        values.hours,
+        values.minutes,
+        values.seconds,
+    )
+    .single()
+    .and_then(|dt| dt.with_nanosecond(values.nanos))
+    .ok_or(ParseError::OutOfRange)
+}
+
+/// Backward-compatible [`parse_datetime`] that discards the error detail.
+pub fn parse_datetime_opt(str: &str) -> Option<DateTime<FixedOffset>> {
+    parse_datetime(str).ok()
+}
+
+/// Whether an ambiguous numeric date like `11/02/2024` should be read as
+/// day-first (`11 Feb`) or month-first (`02 Nov`) when no month name or
+/// other cue disambiguates it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DateOrder {
+    DayFirst,
+    MonthFirst,
+}
+
+/// Tolerant, human-input datetime parser, as opposed to the strict
+/// ISO 8601 state machine used by [`parse_datetime`]. Understands inputs
+/// like `"10 September 2015 10:20"`, `"Sept 11, 2024"`, `"11/02/2024"`
+/// and `"Feb 11 2024 2:15pm"`. Ambiguous numeric dates default to
+/// month-first; use [`parse_datetime_fuzzy_with`] to change that.
+pub fn parse_datetime_fuzzy(str: &str) -> Option<DateTime<FixedOffset>> {
+    parse_datetime_fuzzy_with(str, DateOrder::MonthFirst)
+}
+
+/// Like [`parse_datetime_fuzzy`], but lets the caller pick how ambiguous
+/// numeric dates (no month name, no other cue) are resolved.
+pub fn parse_datetime_fuzzy_with(str: &str, order: DateOrder) -> Option<DateTime<FixedOffset>> {
+    let tokens = FuzzyToken::tokenize(str);
+    let values = DateTimeValues::from_fuzzy_tokens(&tokens, order)?;
     FixedOffset::east_opt(values.timezone.secs()).and_then(|tz| {
         tz.with_ymd_and_hms(
             values.year,
@@ -12,6 +85,7 @@ pub fn parse_datetime(str: &str) -> Option<DateTime<FixedOffset>> {
             values.seconds,
         )
         .single()
+        .and_then(|dt| dt.with_nanosecond(values.nanos))
     })
 }
 
@@ -22,6 +96,7 @@ struct DateTimeValues {
     hours: u32,
     minutes: u32,
     seconds: u32,
+    nanos: u32,
     timezone: TimezoneValues,
 }
 
@@ -34,36 +109,231 @@ impl DateTimeValues {
             hours: 0,
             minutes: 0,
             seconds: 0,
+            nanos: 0,
             timezone: TimezoneValues::default(),
         }
     }
 
-    fn from(str: &str) -> Self {
+    fn from(str: &str) -> Result<Self, ParseError> {
+        if let Some(values) = Self::from_epoch(str)? {
+            return Ok(values);
+        }
+
         let mut values = Self::default();
-        values.parse(str, &ParserState::Year);
-        return values;
+        let bytes = str.as_bytes();
+        let mut cursor = 0usize;
+        values.parse(bytes, &ParserState::Year, &mut cursor)?;
+        if cursor < bytes.len() {
+            return Err(ParseError::TrailingInput { at: cursor });
+        }
+        Ok(values)
     }
 
-    fn parse(&mut self, str: &str, state: &ParserState) {
-        match state {
-            ParserState::Timezone => self.timezone.parse(str, TimezoneParserState::Sign),
-            _ => self.parse_state(str, state),
+    /// Recognizes bare Unix epoch timestamps (seconds or milliseconds since
+    /// 1970-01-01), e.g. `"1707660945"` or `"1707660945000"`. Auto-detection
+    /// only applies to those two canonical widths (10 and 13 digits); any
+    /// other all-digit length is left to the ISO state machine, since it
+    /// could just as easily be a year/month/date run or a basic-format
+    /// date-time. An optional `@` prefix (`"@1707660945"`) forces epoch
+    /// interpretation regardless of length.
+    fn from_epoch(str: &str) -> Result<Option<Self>, ParseError> {
+        let (forced, digits) = match str.strip_prefix('@') {
+            Some(rest) => (true, rest),
+            None => (false, str),
+        };
+
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return if forced {
+                let bad = digits
+                    .bytes()
+                    .position(|b| !b.is_ascii_digit())
+                    .unwrap_or(0);
+                Err(ParseError::UnexpectedToken { at: 1 + bad })
+            } else {
+                Ok(None)
+            };
+        }
+
+        if !forced && !matches!(digits.len(), 10 | 13) {
+            return Ok(None);
         }
+
+        let value: i64 = digits.parse().map_err(|_| ParseError::OutOfRange)?;
+        let (secs, nanos) = if digits.len() > 10 {
+            (value / 1_000, ((value % 1_000) * 1_000_000) as u32)
+        } else {
+            (value, 0)
+        };
+
+        let naive = DateTime::<Utc>::from_timestamp(secs, nanos)
+            .ok_or(ParseError::OutOfRange)?
+            .naive_utc();
+
+        Ok(Some(Self {
+            year: naive.year(),
+            month: naive.month(),
+            date: naive.day(),
+            hours: naive.hour(),
+            minutes: naive.minute(),
+            seconds: naive.second(),
+            nanos: naive.nanosecond(),
+            timezone: TimezoneValues::default(),
+        }))
     }
 
-    fn parse_state(&mut self, str: &str, state: &ParserState) {
-        if let Some(from) = state.parse_from(str) {
-            let size = state.size();
-            let field = str
-                .get(from..from + size)
-                .and_then(|value| state.parse(value));
+    fn from_fuzzy_tokens(tokens: &[FuzzyToken], order: DateOrder) -> Option<Self> {
+        let mut values = Self::default();
+        let mut day_candidates: Vec<u32> = Vec::new();
+        let mut year: Option<i32> = None;
+        let mut month: Option<u32> = None;
+        let mut pm: Option<bool> = None;
+        let mut in_time = false;
+        let mut time_part = 0;
+
+        for (index, token) in tokens.iter().enumerate() {
+            match token {
+                FuzzyToken::Word(word) => {
+                    let lower = word.to_lowercase();
+                    if let Some(value) = month_from_word(&lower) {
+                        month = Some(value);
+                    } else if lower == "am" {
+                        pm = Some(false);
+                    } else if lower == "pm" {
+                        pm = Some(true);
+                    }
+                }
+                FuzzyToken::Number(digits) => {
+                    let value: u32 = digits.parse().ok()?;
+                    let next_is_colon = matches!(
+                        tokens.get(index + 1),
+                        Some(FuzzyToken::Punct(p)) if p.contains(':')
+                    );
+                    let prev_is_colon = index > 0
+                        && matches!(tokens[index - 1], FuzzyToken::Punct(p) if p.contains(':'));
+
+                    if digits.len() == 4 && !in_time {
+                        year = Some(value as i32);
+                    } else if in_time || next_is_colon || prev_is_colon {
+                        in_time = true;
+                        match time_part {
+                            0 => values.hours = value,
+                            1 => values.minutes = value,
+                            _ => values.seconds = value,
+                        }
+                        time_part += 1;
+                    } else {
+                        day_candidates.push(value);
+                    }
+                }
+                FuzzyToken::Punct(_) => {}
+            }
+        }
+
+        if let Some(true) = pm {
+            if values.hours < 12 {
+                values.hours += 12;
+            }
+        } else if let Some(false) = pm {
+            if values.hours == 12 {
+                values.hours = 0;
+            }
+        }
 
-            if let Some(field) = field {
-                if let Some(next) = state.next() {
-                    self.parse(&str[from + size..], &next);
+        match month {
+            Some(month) => {
+                values.month = month;
+                if let Some(day) = day_candidates.first() {
+                    values.date = *day;
                 }
+            }
+            None => {
+                if day_candidates.len() >= 2 {
+                    let (first, second) = (day_candidates[0], day_candidates[1]);
+                    // A number over 12 can't be a month, so that candidate
+                    // must be the day regardless of `order`. Only fall back
+                    // to the order preference when both are <= 12 and the
+                    // assignment is genuinely ambiguous.
+                    let (parsed_month, parsed_date) = if first > 12 && second <= 12 {
+                        (second, first)
+                    } else if second > 12 && first <= 12 {
+                        (first, second)
+                    } else {
+                        match order {
+                            DateOrder::MonthFirst => (first, second),
+                            DateOrder::DayFirst => (second, first),
+                        }
+                    };
+                    values.month = parsed_month;
+                    values.date = parsed_date;
+                    if year.is_none() {
+                        if let Some(third) = day_candidates.get(2) {
+                            year = Some(if *third < 100 {
+                                2000 + *third as i32
+                            } else {
+                                *third as i32
+                            });
+                        }
+                    }
+                } else if let Some(day) = day_candidates.first() {
+                    values.date = *day;
+                }
+            }
+        }
+
+        if let Some(year) = year {
+            values.year = year;
+        }
+
+        Some(values)
+    }
+
+    fn parse(
+        &mut self,
+        bytes: &[u8],
+        state: &ParserState,
+        cursor: &mut usize,
+    ) -> Result<(), ParseError> {
+        match state {
+            ParserState::Timezone => {
+                self.timezone
+                    .parse(bytes, TimezoneParserState::Sign, cursor)
+            }
+            _ => self.parse_state(bytes, state, cursor),
+        }
+    }
+
+    fn parse_state(
+        &mut self,
+        bytes: &[u8],
+        state: &ParserState,
+        cursor: &mut usize,
+    ) -> Result<(), ParseError> {
+        if *cursor >= bytes.len() {
+            return Ok(());
+        }
+
+        match state.read_field(bytes, *cursor) {
+            Ok((field, end)) => {
                 self.set(field);
+                *cursor = end;
+
+                if *state == ParserState::Seconds {
+                    if let Some(consumed) = parse_fraction(bytes, *cursor, &mut self.nanos) {
+                        *cursor += consumed;
+                    }
+                }
             }
+            // Minutes and seconds are optional once a timezone designator
+            // (or the end of input) follows where their digits would be, so
+            // e.g. "14Z" and "14:15+05:00" skip straight to the timezone
+            // instead of erroring on the missing field.
+            Err(_) if state.is_optional() && at_timezone_boundary(bytes, *cursor) => {}
+            Err(err) => return Err(err),
+        }
+
+        match state.next() {
+            Some(next) => self.parse(bytes, &next, cursor),
+            None => Ok(()),
         }
     }
 
@@ -75,11 +345,57 @@ impl DateTimeValues {
             DateTimeField::Hours(value) => self.hours = value,
             DateTimeField::Minutes(value) => self.minutes = value,
             DateTimeField::Seconds(value) => self.seconds = value,
-            DateTimeField::Timezone(value) => self.timezone = value,
         }
     }
 }
 
+/// Reads a `.digits` or `,digits` fractional-second suffix at `cursor`,
+/// normalizing the digit run to nanoseconds (padded or truncated to 9
+/// digits) into `*nanos`. Returns the number of bytes consumed, including
+/// the separator, or `None` if there's no fraction at `cursor`.
+fn parse_fraction(bytes: &[u8], cursor: usize, nanos: &mut u32) -> Option<usize> {
+    match bytes.get(cursor) {
+        Some(b'.') | Some(b',') => {}
+        _ => return None,
+    }
+
+    let digits_start = cursor + 1;
+    let digits_len = bytes[digits_start..]
+        .iter()
+        .take_while(|b| b.is_ascii_digit())
+        .count();
+    if digits_len == 0 {
+        return None;
+    }
+
+    let mut value = 0u32;
+    let mut scale = 100_000_000u32;
+    for &digit in &bytes[digits_start..digits_start + digits_len.min(9)] {
+        value += (digit - b'0') as u32 * scale;
+        scale /= 10;
+    }
+
+    *nanos = value;
+    Some(1 + digits_len)
+}
+
+/// Accumulates an ASCII digit run into a `u32`, `byte - b'0'` at a time,
+/// rather than going through `str::parse`.
+fn parse_uint(digits: &[u8]) -> Option<u32> {
+    if digits.is_empty() {
+        return None;
+    }
+
+    let mut value: u32 = 0;
+    for &byte in digits {
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+        value = value * 10 + (byte - b'0') as u32;
+    }
+    Some(value)
+}
+
 enum DateTimeField {
     Year(i32),
     Month(u32),
@@ -87,11 +403,10 @@ enum DateTimeField {
     Hours(u32),
     Minutes(u32),
     Seconds(u32),
-    Timezone(TimezoneValues),
 }
 
-#[derive(Debug, PartialEq)]
-enum ParserState {
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParserState {
     Year,
     Month,
     Date,
@@ -135,29 +450,63 @@ impl ParserState {
         }
     }
 
-    fn parse(&self, str: &str) -> Option<DateTimeField> {
+    fn parse_digits(&self, digits: &[u8]) -> Option<DateTimeField> {
+        let value = parse_uint(digits)?;
         match self {
-            ParserState::Year => str.parse().ok().map(|v| DateTimeField::Year(v)),
-            ParserState::Month => str.parse().ok().map(|v| DateTimeField::Month(v)),
-            ParserState::Date => str.parse().ok().map(|v| DateTimeField::Date(v)),
-            ParserState::Hours => str.parse().ok().map(|v| DateTimeField::Hours(v)),
-            ParserState::Minutes => str.parse().ok().map(|v| DateTimeField::Minutes(v)),
-            ParserState::Seconds => str.parse().ok().map(|v| DateTimeField::Seconds(v)),
+            ParserState::Year => Some(DateTimeField::Year(value as i32)),
+            ParserState::Month => Some(DateTimeField::Month(value)),
+            ParserState::Date => Some(DateTimeField::Date(value)),
+            ParserState::Hours => Some(DateTimeField::Hours(value)),
+            ParserState::Minutes => Some(DateTimeField::Minutes(value)),
+            ParserState::Seconds => Some(DateTimeField::Seconds(value)),
             _ => None,
         }
     }
 
-    fn parse_from(&self, str: &str) -> Option<usize> {
+    fn parse_from(&self, bytes: &[u8], cursor: usize) -> Option<usize> {
         match self.prefix() {
-            Some(ParserPrefix::Dash) => str.get(..1).map(|s| if s == "-" { 1 } else { 0 }),
-            Some(ParserPrefix::Colon) => str.get(..1).map(|s| if s == ":" { 1 } else { 0 }),
-            Some(ParserPrefix::T) => {
-                str.get(..1)
-                    .and_then(|s| if s == "T" || s == " " { Some(1) } else { None })
-            }
+            Some(ParserPrefix::Dash) => bytes.get(cursor).map(|&b| if b == b'-' { 1 } else { 0 }),
+            Some(ParserPrefix::Colon) => bytes.get(cursor).map(|&b| if b == b':' { 1 } else { 0 }),
+            Some(ParserPrefix::T) => bytes
+                .get(cursor)
+                .and_then(|&b| if b == b'T' || b == b' ' { Some(1) } else { None }),
             None => Some(0),
         }
     }
+
+    /// Reads this field's digits at `cursor`, returning the field and the
+    /// cursor position just past it.
+    fn read_field(&self, bytes: &[u8], cursor: usize) -> Result<(DateTimeField, usize), ParseError> {
+        let from = self
+            .parse_from(bytes, cursor)
+            .ok_or(ParseError::UnexpectedToken { at: cursor })?;
+        let start = cursor + from;
+        let end = start + self.size();
+        let digits = bytes
+            .get(start..end)
+            .ok_or(ParseError::UnexpectedToken { at: start })?;
+        let field = self
+            .parse_digits(digits)
+            .ok_or_else(|| ParseError::InvalidField(self.clone()))?;
+        Ok((field, end))
+    }
+
+    /// Whether this field may be absent entirely, falling through to the
+    /// next state rather than erroring, when it isn't followed by its own
+    /// digits (see [`at_timezone_boundary`]).
+    fn is_optional(&self) -> bool {
+        matches!(self, ParserState::Minutes | ParserState::Seconds)
+    }
+}
+
+/// Whether `cursor` sits at a timezone designator (`Z`/`z`/`+`/`-`) or the
+/// end of input, i.e. a point where an optional minutes/seconds field is
+/// legitimately absent rather than malformed.
+fn at_timezone_boundary(bytes: &[u8], cursor: usize) -> bool {
+    matches!(
+        bytes.get(cursor),
+        None | Some(b'Z') | Some(b'z') | Some(b'+') | Some(b'-')
+    )
 }
 
 enum ParserPrefix {
@@ -185,51 +534,67 @@ impl TimezoneValues {
         (self.hours * 60 + self.minutes) * 60 * if self.sign { 1 } else { -1 }
     }
 
-    fn parse(&mut self, str: &str, state: TimezoneParserState) {
+    fn parse(
+        &mut self,
+        bytes: &[u8],
+        state: TimezoneParserState,
+        cursor: &mut usize,
+    ) -> Result<(), ParseError> {
         match state {
-            TimezoneParserState::Sign => self.parse_sign(str),
-            TimezoneParserState::Hours => self.parse_hours(str),
-            TimezoneParserState::Minutes => self.parse_minutes(str),
+            TimezoneParserState::Sign => self.parse_sign(bytes, cursor),
+            TimezoneParserState::Hours => self.parse_hours(bytes, cursor),
+            TimezoneParserState::Minutes => self.parse_minutes(bytes, cursor),
         }
     }
 
-    fn parse_sign(&mut self, str: &str) {
-        if let Some(first) = str.get(..1) {
-            if first == "+" || first == "-" {
-                self.sign = first == "+";
-
-                if let Some(str) = str.get(1..) {
-                    self.parse(str, TimezoneParserState::Hours)
-                }
+    fn parse_sign(&mut self, bytes: &[u8], cursor: &mut usize) -> Result<(), ParseError> {
+        match bytes.get(*cursor) {
+            Some(b'Z') | Some(b'z') => {
+                self.sign = true;
+                self.hours = 0;
+                self.minutes = 0;
+                *cursor += 1;
+                Ok(())
+            }
+            Some(&sign @ (b'+' | b'-')) => {
+                self.sign = sign == b'+';
+                *cursor += 1;
+                self.parse(bytes, TimezoneParserState::Hours, cursor)
             }
+            _ => Ok(()),
         }
     }
 
-    fn parse_hours(&mut self, str: &str) {
-        if let Some(hours) = str.get(..2).and_then(|v| v.parse::<i32>().ok()) {
-            self.hours = hours;
-
-            if let Some(str) = str.get(2..) {
-                self.parse(str, TimezoneParserState::Minutes)
-            }
-        }
+    fn parse_hours(&mut self, bytes: &[u8], cursor: &mut usize) -> Result<(), ParseError> {
+        let start = *cursor;
+        let hours = bytes
+            .get(start..start + 2)
+            .and_then(parse_uint)
+            .ok_or(ParseError::InvalidField(ParserState::Timezone))?;
+        self.hours = hours as i32;
+        *cursor = start + 2;
+        self.parse(bytes, TimezoneParserState::Minutes, cursor)
     }
 
-    fn parse_minutes(&mut self, str: &str) {
-        match str.get(..1) {
-            Some(":") => {
-                if let Some(str) = str.get(1..) {
-                    self.parse(str, TimezoneParserState::Minutes);
-                }
+    fn parse_minutes(&mut self, bytes: &[u8], cursor: &mut usize) -> Result<(), ParseError> {
+        match bytes.get(*cursor) {
+            Some(b':') => {
+                *cursor += 1;
+                self.parse(bytes, TimezoneParserState::Minutes, cursor)
             }
 
             Some(_) => {
-                if let Some(minutes) = str.get(..2).and_then(|v| v.parse::<i32>().ok()) {
-                    self.minutes = minutes;
-                }
+                let start = *cursor;
+                let minutes = bytes
+                    .get(start..start + 2)
+                    .and_then(parse_uint)
+                    .ok_or(ParseError::InvalidField(ParserState::Timezone))?;
+                self.minutes = minutes as i32;
+                *cursor = start + 2;
+                Ok(())
             }
 
-            None => {}
+            None => Ok(()),
         }
     }
 }
@@ -240,12 +605,71 @@ enum TimezoneParserState {
     Minutes,
 }
 
+#[derive(Debug, PartialEq)]
+enum FuzzyToken<'a> {
+    Number(&'a str),
+    Word(&'a str),
+    Punct(&'a str),
+}
+
+impl<'a> FuzzyToken<'a> {
+    fn tokenize(str: &'a str) -> Vec<Self> {
+        let bytes = str.as_bytes();
+        let mut tokens = Vec::new();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let start = i;
+            let is_digit = bytes[i].is_ascii_digit();
+            let is_alpha = bytes[i].is_ascii_alphabetic();
+
+            if is_digit {
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                tokens.push(FuzzyToken::Number(&str[start..i]));
+            } else if is_alpha {
+                while i < bytes.len() && bytes[i].is_ascii_alphabetic() {
+                    i += 1;
+                }
+                tokens.push(FuzzyToken::Word(&str[start..i]));
+            } else {
+                while i < bytes.len() && !bytes[i].is_ascii_digit() && !bytes[i].is_ascii_alphabetic()
+                {
+                    i += 1;
+                }
+                tokens.push(FuzzyToken::Punct(&str[start..i]));
+            }
+        }
+
+        tokens
+    }
+}
+
+fn month_from_word(word: &str) -> Option<u32> {
+    match word {
+        "jan" | "january" => Some(1),
+        "feb" | "february" => Some(2),
+        "mar" | "march" => Some(3),
+        "apr" | "april" => Some(4),
+        "may" => Some(5),
+        "jun" | "june" => Some(6),
+        "jul" | "july" => Some(7),
+        "aug" | "august" => Some(8),
+        "sep" | "sept" | "september" => Some(9),
+        "oct" | "october" => Some(10),
+        "nov" | "november" => Some(11),
+        "dec" | "december" => Some(12),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     #[test]
     fn test_parse_datetime_from_file() {
-        let values = vec![
+        let values = [
             // Year
             ("2024", "2024-01-01T00:00:00Z"), // #0
             // Month
@@ -293,4 +717,136 @@ mod tests {
     fn chrono_datetime(str: &str) -> DateTime<FixedOffset> {
         DateTime::parse_from_rfc3339(str).unwrap()
     }
+
+    #[test]
+    fn test_parse_datetime_errors() {
+        let values = [
+            ("2024-99-11", ParseError::OutOfRange),
+            ("2024-02-11X14", ParseError::UnexpectedToken { at: 10 }),
+            ("2024-02-11T14:15:45+05:00garbage", ParseError::TrailingInput { at: 25 }),
+            // 14 digits isn't a canonical epoch width (10 or 13), so this
+            // falls through to the ISO state machine instead of being
+            // misread as an epoch-millis timestamp; the machine still
+            // requires a `T`/space before the time-of-day digits.
+            ("20240211141545", ParseError::UnexpectedToken { at: 8 }),
+        ];
+
+        for (index, (input, expected)) in values.iter().enumerate() {
+            let result = parse_datetime(input).unwrap_err();
+            assert_eq!(
+                result, *expected,
+                "Failed to report error for #{index} \"{}\": expected {:?} but got {:?}",
+                input, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_datetime_fractional_seconds_and_zulu() {
+        let values = [
+            (
+                "2024-02-11T14:15:45Z",
+                "2024-02-11T14:15:45Z",
+            ),
+            (
+                "2024-02-11T14:15:45.250Z",
+                "2024-02-11T14:15:45.250Z",
+            ),
+            (
+                "2024-02-11T14:15:45.25+05:00",
+                "2024-02-11T14:15:45.250+05:00",
+            ),
+            (
+                "2024-02-11T14:15:45,250+05:00",
+                "2024-02-11T14:15:45.250+05:00",
+            ),
+            // Minute precision: no seconds field before the timezone.
+            ("2024-02-11T14:15Z", "2024-02-11T14:15:00Z"),
+            ("2024-02-11T14:15+05:00", "2024-02-11T14:15:00+05:00"),
+            ("2024-02-11T14:15-04:30", "2024-02-11T14:15:00-04:30"),
+            // Hour precision: no minutes or seconds before the timezone.
+            ("2024-02-11T14Z", "2024-02-11T14:00:00Z"),
+            ("2024-02-11T14+05:00", "2024-02-11T14:00:00+05:00"),
+        ];
+
+        for (index, (input, expected)) in values.iter().enumerate() {
+            let result = parse_datetime(input).unwrap();
+            assert_eq!(
+                result,
+                chrono_datetime(expected),
+                "Failed to parse date #{index} \"{}\": expected \"{}\" but got \"{}\"",
+                input,
+                expected,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_datetime_epoch() {
+        let values = [
+            ("1707660945", "2024-02-11T14:15:45Z"),
+            ("1707660945000", "2024-02-11T14:15:45Z"),
+            ("@1707660945", "2024-02-11T14:15:45Z"),
+        ];
+
+        for (index, (input, expected)) in values.iter().enumerate() {
+            let result = parse_datetime(input).unwrap();
+            assert_eq!(
+                result,
+                chrono_datetime(expected),
+                "Failed to parse date #{index} \"{}\": expected \"{}\" but got \"{}\"",
+                input,
+                expected,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_datetime_fuzzy() {
+        let values = [
+            ("10 September 2015 10:20", "2015-09-10T10:20:00Z"), // #0
+            ("Sept 11, 2024", "2024-09-11T00:00:00Z"),           // #1
+            ("11/02/2024", "2024-11-02T00:00:00Z"), // #2: month-first default
+            ("Feb 11 2024 2:15pm", "2024-02-11T14:15:00Z"), // #3
+            ("Dec 25 2024 12:00am", "2024-12-25T00:00:00Z"), // #4: 12am is midnight
+            ("Dec 25 2024 12:00pm", "2024-12-25T12:00:00Z"), // #5: 12pm is noon
+            ("25/12/2024", "2024-12-25T00:00:00Z"), // #6: unambiguous regardless of order
+        ];
+
+        for (index, (input, expected)) in values.iter().enumerate() {
+            let result = parse_datetime_fuzzy(input).unwrap();
+            assert_eq!(
+                result,
+                chrono_datetime(expected),
+                "Failed to parse date #{index} \"{}\": expected \"{}\" but got \"{}\"",
+                input,
+                expected,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_datetime_fuzzy_with_order() {
+        let values = [
+            ("11/02/2024", DateOrder::MonthFirst, "2024-11-02T00:00:00Z"), // #0
+            ("11/02/2024", DateOrder::DayFirst, "2024-02-11T00:00:00Z"),   // #1
+            ("25/12/2024", DateOrder::MonthFirst, "2024-12-25T00:00:00Z"), // #2: unambiguous
+            ("25/12/2024", DateOrder::DayFirst, "2024-12-25T00:00:00Z"),   // #3: unambiguous
+        ];
+
+        for (index, (input, order, expected)) in values.iter().enumerate() {
+            let result = parse_datetime_fuzzy_with(input, *order).unwrap();
+            assert_eq!(
+                result,
+                chrono_datetime(expected),
+                "Failed to parse date #{index} \"{}\": expected \"{}\" but got \"{}\"",
+                input,
+                expected,
+                result
+            );
+        }
+    }
 }